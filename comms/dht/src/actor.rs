@@ -37,12 +37,13 @@ use crate::{
         OutboundMessageRequester,
     },
     proto::{
-        dht::{DiscoverMessage, JoinMessage},
+        dht::{DiscoverMessage, DiscoveryResponseMessage, JoinMessage},
         envelope::DhtMessageType,
         store_forward::StoredMessagesRequest,
     },
     DhtConfig,
 };
+use chrono::{DateTime, Utc};
 use derive_error::Error;
 use futures::{
     channel::{mpsc, mpsc::SendError, oneshot},
@@ -51,17 +52,62 @@ use futures::{
     SinkExt,
     StreamExt,
 };
+use futures_timer::{Delay, Interval};
 use log::*;
-use std::sync::Arc;
+use rand::{rngs::OsRng, Rng, RngCore};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tari_comms::{
-    peer_manager::{NodeId, NodeIdentity},
-    types::CommsPublicKey,
+    peer_manager::{NodeId, NodeIdentity, Peer},
+    types::{CommsPublicKey, CommsSecretKey, Signature},
 };
+use tari_crypto::keys::PublicKey;
 use tari_shutdown::ShutdownSignal;
 use tari_utilities::ByteArray;
 use ttl_cache::TtlCache;
 
 const LOG_TARGET: &'static str = "comms::dht::actor";
+/// How often pending discovery requests are checked for expiry
+const DISCOVERY_SWEEP_INTERVAL: Duration = Duration::from_secs(1);
+/// Sentinel duration used to emulate a "disabled" timer: long enough that, in practice, a refresh interval of
+/// zero in `DhtConfig` never fires during the process lifetime.
+const DISABLED_TIMER_DURATION: Duration = Duration::from_secs(60 * 60 * 24 * 365);
+/// Penalty applied to a peer that sends a Join/Discover message with an invalid gossip signature, node id
+/// mapping, or timestamp.
+const INVALID_GOSSIP_SIGNATURE_PENALTY: i64 = 25;
+
+/// Domain-separation tag (and, for `Discover`/`DiscoveryResponse`, the correlation nonce) folded into the signed
+/// buffer for inbound gossip self-announcements. The tag stops a signature produced for one message kind being
+/// replayed and accepted as another (e.g. a broadcast Join repackaged as a `DiscoveryResponseMessage`); the
+/// nonce stops a genuine `DiscoveryResponseMessage` for one query being replayed with a different, attacker-
+/// chosen `nonce` to complete someone else's unrelated pending discovery.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GossipMessageKind {
+    Join,
+    Discover { nonce: u64 },
+    DiscoveryResponse { nonce: u64 },
+}
+
+impl GossipMessageKind {
+    fn domain_tag(self) -> u8 {
+        match self {
+            GossipMessageKind::Join => 1,
+            GossipMessageKind::Discover { .. } => 2,
+            GossipMessageKind::DiscoveryResponse { .. } => 3,
+        }
+    }
+
+    fn nonce(self) -> u64 {
+        match self {
+            GossipMessageKind::Join => 0,
+            GossipMessageKind::Discover { nonce } | GossipMessageKind::DiscoveryResponse { nonce } => nonce,
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum DhtActorError {
@@ -71,6 +117,20 @@ pub enum DhtActorError {
     SendBufferFull,
     /// Reply sender canceled the request
     ReplyCanceled,
+    /// Failed to sign outbound message
+    FailedToSignMessage,
+    /// The signature on a received message is invalid
+    InvalidMessageSignature,
+    /// The node id of a received message does not match the public key that signed it
+    InvalidNodeIdMapping,
+    /// A received message's `created_at` field lies outside the allowed clock skew window
+    MessageTimestampOutOfRange,
+    /// The discovery request timed out waiting for a response
+    DiscoveryTimeout,
+    /// A `DiscoveryResponseMessage` did not contain valid peer data
+    InvalidPeerData,
+    /// Failed to send an outbound DHT message
+    DhtOutboundError(DhtOutboundError),
 }
 
 impl From<SendError> for DhtActorError {
@@ -85,16 +145,82 @@ impl From<SendError> for DhtActorError {
     }
 }
 
+/// Tracks the high-water mark of stored-message (SAF) requests, keyed by the requesting node, so that
+/// `DhtActor` only asks for messages newer than the last successful sync rather than re-downloading the
+/// entire store after every restart.
+pub trait SafStateStore: Send {
+    /// Returns the timestamp of the last successful SAF request made by `source`, if any
+    fn last_request_time(&self, source: &NodeId) -> Option<DateTime<Utc>>;
+    /// Records that `source` successfully made a SAF request at `time`
+    fn set_last_request_time(&mut self, source: NodeId, time: DateTime<Utc>);
+}
+
+/// A non-persisted `SafStateStore` that is reset on every restart. Suitable as a default for nodes that don't
+/// need to avoid a full resync after a restart.
+#[derive(Default)]
+pub struct InMemorySafStateStore {
+    last_request_times: HashMap<NodeId, DateTime<Utc>>,
+}
+
+impl SafStateStore for InMemorySafStateStore {
+    fn last_request_time(&self, source: &NodeId) -> Option<DateTime<Utc>> {
+        self.last_request_times.get(source).copied()
+    }
+
+    fn set_last_request_time(&mut self, source: NodeId, time: DateTime<Utc>) {
+        self.last_request_times.insert(source, time);
+    }
+}
+
 #[derive(Debug)]
 pub enum DhtRequest {
     /// Send a Join request to the network
     SendJoin,
-    /// Send a discover request for a network region or node
+    /// Send a discover request for a network region or node, resolving once a matching
+    /// `DiscoveryResponseMessage` is received or the discovery times out
     SendDiscover {
         dest_public_key: CommsPublicKey,
         dest_node_id: Option<NodeId>,
         destination: NodeDestination,
+        reply_tx: oneshot::Sender<Result<Peer, DhtActorError>>,
+    },
+    /// Handles an inbound discovery response, verifying it against `origin_public_key` (see
+    /// `verify_gossip_message`) before completing the pending discovery with the matching nonce (if any). A
+    /// response that fails verification is dropped and the sending peer is penalized, exactly like a forged
+    /// Join/Discover. A response whose nonce matches no pending discovery is ignored without being verified.
+    DiscoveryResponseReceived {
+        origin_public_key: CommsPublicKey,
+        message: Box<DiscoveryResponseMessage>,
+    },
+    /// Handles an inbound Join message, verifying its signature, node id mapping, and timestamp (see
+    /// `verify_gossip_message`) before accepting it. Messages that fail verification are dropped and the
+    /// sending peer is penalized.
+    JoinReceived {
+        origin_public_key: CommsPublicKey,
+        message: Box<JoinMessage>,
     },
+    /// Handles an inbound Discover message, verifying its signature, node id mapping, and timestamp (see
+    /// `verify_gossip_message`) before accepting it. Messages that fail verification are dropped and the
+    /// sending peer is penalized.
+    DiscoverReceived {
+        origin_public_key: CommsPublicKey,
+        message: Box<DiscoverMessage>,
+    },
+    /// Records a penalty against a peer (e.g. invalid gossip signature, SAF flooding, unsolicited discover reply).
+    /// Penalty points decay over time; once a peer's total crosses `config.ban_threshold`, it is excluded from
+    /// closest-peer broadcasts
+    PenalizePeer { node_id: NodeId, penalty: i64 },
+    /// Returns a peer's current (decayed) penalty score
+    GetPeerScore(NodeId, oneshot::Sender<i64>),
+    /// Sends a stored-message (SAF) request. If `since` is `None`, the persisted high-water mark from the
+    /// `SafStateStore` is used (or a full resync is requested if no mark exists); pass `Some(_)` to force a
+    /// resync from a specific point in time.
+    RequestStoredMessages { since: Option<DateTime<Utc>> },
+    /// Reports that this node's most recent stored-message (SAF) request has been successfully processed (its
+    /// response's messages stored), advancing this node's persisted high-water mark to `last_message_timestamp`
+    /// so that the next sync only asks for messages newer than this. This must only be sent once the response's
+    /// messages have actually been applied, not merely once the request has been sent.
+    SafMessagesProcessed { last_message_timestamp: DateTime<Utc> },
     /// Inserts a message signature to the signature cache. This operation replies with a boolean
     /// which is true if the signature already exists in the cache, otherwise false
     SignatureCacheInsert(Box<Vec<u8>>, oneshot::Sender<bool>),
@@ -114,21 +240,27 @@ impl DhtRequester {
         self.sender.send(DhtRequest::SendJoin).await.map_err(Into::into)
     }
 
+    /// Sends a Discover message and awaits a matching `DiscoveryResponseMessage`, resolving to the discovered
+    /// peer. Resolves to `Err(DhtActorError::DiscoveryTimeout)` if no response is received within
+    /// `DhtConfig::discovery_request_timeout`.
     pub async fn send_discover(
         &mut self,
         dest_public_key: CommsPublicKey,
         dest_node_id: Option<NodeId>,
         destination: NodeDestination,
-    ) -> Result<(), DhtActorError>
+    ) -> Result<Peer, DhtActorError>
     {
+        let (reply_tx, reply_rx) = oneshot::channel();
         self.sender
             .send(DhtRequest::SendDiscover {
                 dest_public_key,
                 dest_node_id,
                 destination,
+                reply_tx,
             })
-            .await
-            .map_err(Into::into)
+            .await?;
+
+        reply_rx.await.map_err(|_| DhtActorError::ReplyCanceled)?
     }
 
     pub async fn insert_message_signature(&mut self, signature: Vec<u8>) -> Result<bool, DhtActorError> {
@@ -139,6 +271,45 @@ impl DhtRequester {
 
         reply_rx.await.map_err(|_| DhtActorError::ReplyCanceled)
     }
+
+    /// Reports misbehaviour by `node_id`, adding `penalty` points to its (decaying) reputation score
+    pub async fn penalize_peer(&mut self, node_id: NodeId, penalty: i64) -> Result<(), DhtActorError> {
+        self.sender
+            .send(DhtRequest::PenalizePeer { node_id, penalty })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Returns `node_id`'s current (decayed) penalty score
+    pub async fn get_peer_score(&mut self, node_id: NodeId) -> Result<i64, DhtActorError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender.send(DhtRequest::GetPeerScore(node_id, reply_tx)).await?;
+
+        reply_rx.await.map_err(|_| DhtActorError::ReplyCanceled)
+    }
+
+    /// Sends a stored-message (SAF) request. Pass `None` to use the persisted high-water mark (the default
+    /// periodic behaviour), or `Some(since)` to force a resync from a specific point in time.
+    pub async fn request_stored_messages(&mut self, since: Option<DateTime<Utc>>) -> Result<(), DhtActorError> {
+        self.sender
+            .send(DhtRequest::RequestStoredMessages { since })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Notifies the DHT actor that this node's most recent stored-message (SAF) request has been successfully
+    /// processed, so that its high-water mark can be advanced to `last_message_timestamp`. Call this only after
+    /// the response's messages have actually been stored.
+    pub async fn notify_saf_messages_processed(
+        &mut self,
+        last_message_timestamp: DateTime<Utc>,
+    ) -> Result<(), DhtActorError>
+    {
+        self.sender
+            .send(DhtRequest::SafMessagesProcessed { last_message_timestamp })
+            .await
+            .map_err(Into::into)
+    }
 }
 
 pub struct DhtActor {
@@ -148,6 +319,9 @@ pub struct DhtActor {
     shutdown_signal: Option<ShutdownSignal>,
     request_rx: Fuse<mpsc::Receiver<DhtRequest>>,
     signature_cache: TtlCache<Vec<u8>, ()>,
+    pending_discoveries: HashMap<u64, (oneshot::Sender<Result<Peer, DhtActorError>>, Instant)>,
+    peer_scores: TtlCache<NodeId, (i64, Instant)>,
+    saf_state_store: Box<dyn SafStateStore>,
 }
 
 impl DhtActor {
@@ -158,14 +332,38 @@ impl DhtActor {
         request_rx: mpsc::Receiver<DhtRequest>,
         shutdown_signal: ShutdownSignal,
     ) -> Self
+    {
+        Self::with_saf_state_store(
+            config,
+            node_identity,
+            outbound_requester,
+            request_rx,
+            shutdown_signal,
+            Box::new(InMemorySafStateStore::default()),
+        )
+    }
+
+    /// As per [`DhtActor::new`], but with an explicit `SafStateStore` so that the SAF high-water mark can be
+    /// persisted across restarts
+    pub fn with_saf_state_store(
+        config: DhtConfig,
+        node_identity: Arc<NodeIdentity>,
+        outbound_requester: OutboundMessageRequester,
+        request_rx: mpsc::Receiver<DhtRequest>,
+        shutdown_signal: ShutdownSignal,
+        saf_state_store: Box<dyn SafStateStore>,
+    ) -> Self
     {
         Self {
             signature_cache: TtlCache::new(config.signature_cache_capacity),
+            peer_scores: TtlCache::new(config.peer_score_cache_capacity),
             config,
             outbound_requester,
             node_identity,
             shutdown_signal: Some(shutdown_signal),
             request_rx: request_rx.fuse(),
+            pending_discoveries: HashMap::new(),
+            saf_state_store,
         }
     }
 
@@ -185,7 +383,7 @@ impl DhtActor {
         }
 
         if self.config.enable_auto_stored_message_request {
-            match self.request_stored_messages().await {
+            match self.request_stored_messages(None).await {
                 Ok(_) => {
                     trace!(
                         target: LOG_TARGET,
@@ -207,6 +405,10 @@ impl DhtActor {
             .expect("DhtActor initialized without shutdown_signal")
             .fuse();
 
+        let mut discovery_sweep_timer = Interval::new(DISCOVERY_SWEEP_INTERVAL).fuse();
+        let mut join_refresh_timer = self.jittered_delay(self.config.join_refresh_interval).fuse();
+        let mut saf_refresh_timer = self.jittered_delay(self.config.saf_refresh_interval).fuse();
+
         loop {
             futures::select! {
                 request = self.request_rx.select_next_some() => {
@@ -214,6 +416,28 @@ impl DhtActor {
                     self.handle_request(request).await;
                 },
 
+                _ = discovery_sweep_timer.select_next_some() => {
+                    self.sweep_expired_discoveries();
+                },
+
+                _ = join_refresh_timer => {
+                    if self.config.join_refresh_interval.as_nanos() != 0 {
+                        if let Err(err) = self.send_join().await {
+                            error!(target: LOG_TARGET, "Failed to send periodic re-join message: {}", err);
+                        }
+                    }
+                    join_refresh_timer = self.jittered_delay(self.config.join_refresh_interval).fuse();
+                },
+
+                _ = saf_refresh_timer => {
+                    if self.config.saf_refresh_interval.as_nanos() != 0 {
+                        if let Err(err) = self.request_stored_messages(None).await {
+                            error!(target: LOG_TARGET, "Failed to send periodic SAF refresh request: {}", err);
+                        }
+                    }
+                    saf_refresh_timer = self.jittered_delay(self.config.saf_refresh_interval).fuse();
+                },
+
                 _guard = shutdown_signal => {
                     info!(target: LOG_TARGET, "DHtActor is shutting down because it received a shutdown signal.");
                     break;
@@ -229,12 +453,55 @@ impl DhtActor {
     async fn handle_request(&mut self, request: DhtRequest) {
         use DhtRequest::*;
         let result = match request {
-            SendJoin => self.send_join().await,
+            SendJoin => self.send_join().await.map_err(Into::into),
             SendDiscover {
                 destination,
                 dest_node_id,
                 dest_public_key,
-            } => self.send_discover(dest_public_key, dest_node_id, destination).await,
+                reply_tx,
+            } => self.initiate_discover(dest_public_key, dest_node_id, destination, reply_tx).await,
+
+            DiscoveryResponseReceived {
+                origin_public_key,
+                message,
+            } => {
+                self.handle_discovery_response(origin_public_key, *message);
+                Ok(())
+            },
+
+            JoinReceived {
+                origin_public_key,
+                message,
+            } => {
+                self.handle_join_received(origin_public_key, *message);
+                Ok(())
+            },
+
+            DiscoverReceived {
+                origin_public_key,
+                message,
+            } => {
+                self.handle_discover_received(origin_public_key, *message);
+                Ok(())
+            },
+
+            PenalizePeer { node_id, penalty } => {
+                self.penalize_peer(node_id, penalty);
+                Ok(())
+            },
+
+            GetPeerScore(node_id, reply_tx) => {
+                let _ = reply_tx.send(self.peer_score(&node_id));
+                Ok(())
+            },
+
+            RequestStoredMessages { since } => self.request_stored_messages(since).await.map_err(Into::into),
+
+            SafMessagesProcessed { last_message_timestamp } => {
+                let source = self.node_identity.node_id().clone();
+                self.saf_state_store.set_last_request_time(source, last_message_timestamp);
+                Ok(())
+            },
 
             SignatureCacheInsert(signature, reply_tx) => {
                 let already_exists = self
@@ -256,11 +523,331 @@ impl DhtActor {
         }
     }
 
-    async fn send_join(&mut self) -> Result<(), DhtOutboundError> {
+    /// Sends a Discover message carrying a fresh random nonce and stores a pending entry so that the matching
+    /// `DiscoveryResponseMessage` (or a sweep timeout) can resolve `reply_tx`.
+    async fn initiate_discover(
+        &mut self,
+        dest_public_key: CommsPublicKey,
+        dest_node_id: Option<NodeId>,
+        destination: NodeDestination,
+        reply_tx: oneshot::Sender<Result<Peer, DhtActorError>>,
+    ) -> Result<(), DhtActorError>
+    {
+        let nonce = OsRng.next_u64();
+        match self
+            .send_discover(nonce, dest_public_key, dest_node_id, destination)
+            .await
+        {
+            Ok(_) => {
+                self.pending_discoveries.insert(nonce, (reply_tx, Instant::now()));
+                Ok(())
+            },
+            Err(err) => {
+                error!(target: LOG_TARGET, "Failed to send Discover message: {}", err);
+                let _ = reply_tx.send(Err(err));
+                Ok(())
+            },
+        }
+    }
+
+    /// Matches an inbound `DiscoveryResponseMessage` against its pending entry (by nonce), resolving the waiting
+    /// caller with the discovered peer. Responses with an unknown (or already-fulfilled) nonce are ignored.
+    fn handle_discovery_response(&mut self, origin_public_key: CommsPublicKey, message: DiscoveryResponseMessage) {
+        let nonce = message.nonce;
+
+        if !self.pending_discoveries.contains_key(&nonce) {
+            debug!(
+                target: LOG_TARGET,
+                "Ignoring DiscoveryResponseMessage with unknown or already-resolved nonce {}", nonce
+            );
+            return;
+        }
+
+        let is_valid = self.verify_gossip_message_or_penalize(
+            &origin_public_key,
+            &message.node_id,
+            &message.addresses,
+            message.peer_features,
+            message.created_at,
+            &message.signature,
+            GossipMessageKind::DiscoveryResponse { nonce },
+            &format!("DiscoveryResponseMessage for nonce {}", nonce),
+        );
+        if !is_valid {
+            return;
+        }
+
+        let (reply_tx, _) = self
+            .pending_discoveries
+            .remove(&nonce)
+            .expect("pending_discoveries contains nonce, checked above");
+        let result = Peer::try_from(message).map_err(|_| DhtActorError::InvalidPeerData);
+        let _ = reply_tx.send(result);
+    }
+
+    /// Verifies an inbound Join message against `origin_public_key` before accepting it. A message that fails
+    /// verification (forged signature, mismatched node id, or stale timestamp) is dropped and the sending peer
+    /// is penalized instead of being treated as a legitimate Join.
+    fn handle_join_received(&mut self, origin_public_key: CommsPublicKey, message: JoinMessage) {
+        self.verify_gossip_message_or_penalize(
+            &origin_public_key,
+            &message.node_id,
+            &message.addresses,
+            message.peer_features,
+            message.created_at,
+            &message.signature,
+            GossipMessageKind::Join,
+            "Join message",
+        );
+    }
+
+    /// Verifies an inbound Discover message against `origin_public_key` before accepting it. A message that
+    /// fails verification (forged signature, mismatched node id, or stale timestamp) is dropped and the
+    /// sending peer is penalized instead of being treated as a legitimate Discover.
+    fn handle_discover_received(&mut self, origin_public_key: CommsPublicKey, message: DiscoverMessage) {
+        self.verify_gossip_message_or_penalize(
+            &origin_public_key,
+            &message.node_id,
+            &message.addresses,
+            message.peer_features,
+            message.created_at,
+            &message.signature,
+            GossipMessageKind::Discover { nonce: message.nonce },
+            "Discover message",
+        );
+    }
+
+    /// Shared verify-or-penalize path for all three inbound gossip message handlers (`JoinReceived`,
+    /// `DiscoverReceived`, `DiscoveryResponseReceived`): runs `verify_gossip_message` and, on failure, logs and
+    /// penalizes `origin_public_key`'s node id instead of treating the message as legitimate. A message whose
+    /// signature verifies cryptographically but has already been seen (an exact replay of a prior self-
+    /// announcement) is also rejected and penalized, using the existing `signature_cache`; this check runs after
+    /// `verify_gossip_message`, whose message-kind/nonce binding does not stop an exact replay. `context` names the message kind (and any identifying detail, e.g. a
+    /// nonce) for the log line. Returns whether the message passed verification.
+    fn verify_gossip_message_or_penalize(
+        &mut self,
+        origin_public_key: &CommsPublicKey,
+        node_id: &[u8],
+        addresses: &[String],
+        peer_features: u32,
+        created_at: i64,
+        signature: &[u8],
+        message_kind: GossipMessageKind,
+        context: &str,
+    ) -> bool
+    {
+        if let Err(err) = Self::verify_gossip_message(
+            origin_public_key,
+            node_id,
+            addresses,
+            peer_features,
+            created_at,
+            signature,
+            message_kind,
+            self.config.max_message_clock_skew,
+        ) {
+            warn!(
+                target: LOG_TARGET,
+                "Rejecting {} from '{}': {}", context, origin_public_key, err
+            );
+            if let Ok(node_id) = NodeId::from_key(origin_public_key) {
+                self.penalize_peer(node_id, INVALID_GOSSIP_SIGNATURE_PENALTY);
+            }
+            return false;
+        }
+
+        let already_seen = self
+            .signature_cache
+            .insert(signature.to_vec(), (), self.config.signature_cache_ttl)
+            .is_some();
+        if already_seen {
+            warn!(
+                target: LOG_TARGET,
+                "Rejecting {} from '{}': exact signature replay", context, origin_public_key
+            );
+            if let Ok(node_id) = NodeId::from_key(origin_public_key) {
+                self.penalize_peer(node_id, INVALID_GOSSIP_SIGNATURE_PENALTY);
+            }
+            return false;
+        }
+
+        true
+    }
+
+    /// Completes any pending discovery that has been waiting longer than `config.discovery_request_timeout` with
+    /// `Err(DiscoveryTimeout)`, and removes it from `pending_discoveries`.
+    fn sweep_expired_discoveries(&mut self) {
+        let timeout = self.config.discovery_request_timeout;
+        let expired: Vec<u64> = self
+            .pending_discoveries
+            .iter()
+            .filter(|(_, (_, started_at))| started_at.elapsed() >= timeout)
+            .map(|(nonce, _)| *nonce)
+            .collect();
+
+        for nonce in expired {
+            if let Some((reply_tx, _)) = self.pending_discoveries.remove(&nonce) {
+                let _ = reply_tx.send(Err(DhtActorError::DiscoveryTimeout));
+            }
+        }
+    }
+
+    /// Returns a `Delay` that fires after `base_interval`, jittered by up to `±config.timer_jitter_factor` of
+    /// `base_interval` so that many nodes started at the same time don't re-broadcast in lockstep. A zero
+    /// `base_interval` is treated as "disabled" and fires after `DISABLED_TIMER_DURATION` instead.
+    fn jittered_delay(&self, base_interval: Duration) -> Delay {
+        if base_interval.as_nanos() == 0 {
+            return Delay::new(DISABLED_TIMER_DURATION);
+        }
+
+        let jitter_factor = self.config.timer_jitter_factor.max(0.0);
+        if jitter_factor == 0.0 {
+            return Delay::new(base_interval);
+        }
+
+        let jitter = OsRng.gen_range(-jitter_factor, jitter_factor);
+        let jittered_secs = (base_interval.as_secs_f64() * (1.0 + jitter)).max(0.0);
+        Delay::new(Duration::from_secs_f64(jittered_secs))
+    }
+
+    /// Applies time-based decay to a raw score, subtracting `config.score_decay_amount` for every
+    /// `config.score_decay_interval` that has elapsed since it was last updated, floored at zero.
+    fn decay_score(&self, raw_score: i64, last_updated: Instant) -> i64 {
+        let decay_interval = self.config.score_decay_interval.as_nanos();
+        if decay_interval == 0 {
+            return raw_score;
+        }
+        let elapsed_intervals = (last_updated.elapsed().as_nanos() / decay_interval) as i64;
+        let decayed = raw_score - elapsed_intervals * self.config.score_decay_amount;
+        decayed.max(0)
+    }
+
+    /// Returns `node_id`'s current penalty score, after applying decay
+    fn peer_score(&self, node_id: &NodeId) -> i64 {
+        match self.peer_scores.get(node_id) {
+            Some((raw_score, last_updated)) => self.decay_score(*raw_score, *last_updated),
+            None => 0,
+        }
+    }
+
+    /// Records a penalty against `node_id`, decaying its existing score first so that repeated offences don't
+    /// stack on top of points that should already have expired. Entries are kept in a `TtlCache` (rather than
+    /// an unbounded map) so that a stream of garbage-signed messages from disposable keys cannot grow this
+    /// state without bound.
+    fn penalize_peer(&mut self, node_id: NodeId, penalty: i64) {
+        let current_score = self.peer_score(&node_id);
+        self.peer_scores.insert(
+            node_id,
+            (current_score + penalty, Instant::now()),
+            self.config.peer_score_ttl,
+        );
+    }
+
+    /// Returns the node ids whose current (decayed) score exceeds `config.ban_threshold`, to be merged into
+    /// `excluded_peers` for `BroadcastStrategy::Closest` selection
+    fn banned_peers(&self) -> Vec<NodeId> {
+        self.peer_scores
+            .iter()
+            .filter(|(_, (raw_score, last_updated))| {
+                self.decay_score(*raw_score, *last_updated) > self.config.ban_threshold
+            })
+            .map(|(node_id, _)| node_id.clone())
+            .collect()
+    }
+
+    /// Builds the canonical buffer that is signed (and verified) for Join/Discover gossip messages:
+    /// `node_id || addresses (sorted lexicographically, each prefixed by its length as a u32 LE) ||
+    /// peer_features (LE) || created_at (LE) ||
+    /// message_kind.domain_tag() || message_kind.nonce() (LE)`. The domain tag ties the signature to a specific
+    /// message kind so that a signature produced for one (e.g. a Join) cannot be replayed and accepted as
+    /// another; the nonce (zero for `Join`) additionally ties a `Discover`/`DiscoveryResponse` signature to one
+    /// specific correlation nonce.
+    fn construct_signable_buffer(
+        node_id: &[u8],
+        addresses: &[String],
+        peer_features: u32,
+        created_at: i64,
+        message_kind: GossipMessageKind,
+    ) -> Vec<u8>
+    {
+        let mut sorted_addresses = addresses.to_vec();
+        sorted_addresses.sort();
+
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(node_id);
+        for address in &sorted_addresses {
+            // Length-prefix each address so that two distinct address lists can never concatenate to the same
+            // bytes (which would let a relay shift the boundary between entries and still pass verification).
+            buffer.extend_from_slice(&(address.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(address.as_bytes());
+        }
+        buffer.extend_from_slice(&peer_features.to_le_bytes());
+        buffer.extend_from_slice(&created_at.to_le_bytes());
+        buffer.push(message_kind.domain_tag());
+        buffer.extend_from_slice(&message_kind.nonce().to_le_bytes());
+        buffer
+    }
+
+    /// Signs `buffer` using this node's identity secret key, returning the signature bytes to attach to an
+    /// outbound gossip message.
+    fn sign_gossip_message(&self, buffer: &[u8]) -> Result<Vec<u8>, DhtActorError> {
+        Signature::sign(self.node_identity.secret_key().clone(), buffer)
+            .map(|sig| sig.to_bytes())
+            .map_err(|_| DhtActorError::FailedToSignMessage)
+    }
+
+    /// Verifies the signature on a received Join/Discover message, checking that:
+    /// 1. `node_id` is the hash of the public key that produced `signature`
+    /// 2. `signature` is valid over the canonical signable buffer
+    /// 3. `created_at` falls within `max_skew` of now
+    pub(crate) fn verify_gossip_message(
+        public_key: &CommsPublicKey,
+        node_id: &[u8],
+        addresses: &[String],
+        peer_features: u32,
+        created_at: i64,
+        signature: &[u8],
+        message_kind: GossipMessageKind,
+        max_skew: chrono::Duration,
+    ) -> Result<(), DhtActorError>
+    {
+        if NodeId::from_key(public_key).map_err(|_| DhtActorError::InvalidNodeIdMapping)?.to_vec() != node_id {
+            return Err(DhtActorError::InvalidNodeIdMapping);
+        }
+
+        let now = Utc::now().timestamp();
+        // `created_at` is attacker-controlled (the node id mapping check above only proves self-consistency, not
+        // legitimacy), so avoid unchecked subtraction: an adversarial `created_at` such as `i64::MIN` must be
+        // rejected rather than overflow.
+        match now.checked_sub(created_at) {
+            Some(skew) if skew.unsigned_abs() <= max_skew.num_seconds().unsigned_abs() => {},
+            _ => return Err(DhtActorError::MessageTimestampOutOfRange),
+        }
+
+        let buffer = Self::construct_signable_buffer(node_id, addresses, peer_features, created_at, message_kind);
+        let signature = Signature::from_bytes(signature).map_err(|_| DhtActorError::InvalidMessageSignature)?;
+        if !signature.verify_challenge(public_key, &buffer) {
+            return Err(DhtActorError::InvalidMessageSignature);
+        }
+
+        Ok(())
+    }
+
+    async fn send_join(&mut self) -> Result<(), DhtActorError> {
+        let node_id = self.node_identity.node_id().to_vec();
+        let addresses = vec![self.node_identity.control_service_address().to_string()];
+        let peer_features = self.node_identity.features().bits();
+        let created_at = Utc::now().timestamp();
+        let signable_buffer =
+            Self::construct_signable_buffer(&node_id, &addresses, peer_features, created_at, GossipMessageKind::Join);
+        let signature = self.sign_gossip_message(&signable_buffer)?;
+
         let message = JoinMessage {
-            node_id: self.node_identity.node_id().to_vec(),
-            addresses: vec![self.node_identity.control_service_address().to_string()],
-            peer_features: self.node_identity.features().bits(),
+            node_id,
+            addresses,
+            peer_features,
+            created_at,
+            signature,
         };
 
         debug!(
@@ -273,7 +860,7 @@ impl DhtActor {
                 BroadcastStrategy::Closest(Box::new(BroadcastClosestRequest {
                     n: self.config.num_neighbouring_nodes,
                     node_id: self.node_identity.node_id().clone(),
-                    excluded_peers: Vec::new(),
+                    excluded_peers: self.banned_peers(),
                 })),
                 NodeDestination::Unknown,
                 OutboundEncryption::None,
@@ -287,15 +874,32 @@ impl DhtActor {
 
     async fn send_discover(
         &mut self,
+        nonce: u64,
         dest_public_key: CommsPublicKey,
         dest_node_id: Option<NodeId>,
         destination: NodeDestination,
-    ) -> Result<(), DhtOutboundError>
+    ) -> Result<(), DhtActorError>
     {
+        let node_id = self.node_identity.node_id().to_vec();
+        let addresses = vec![self.node_identity.control_service_address().to_string()];
+        let peer_features = self.node_identity.features().bits();
+        let created_at = Utc::now().timestamp();
+        let signable_buffer = Self::construct_signable_buffer(
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            GossipMessageKind::Discover { nonce },
+        );
+        let signature = self.sign_gossip_message(&signable_buffer)?;
+
         let discover_msg = DiscoverMessage {
-            node_id: self.node_identity.node_id().to_vec(),
-            addresses: vec![self.node_identity.control_service_address().to_string()],
-            peer_features: self.node_identity.features().bits(),
+            node_id,
+            addresses,
+            peer_features,
+            created_at,
+            signature,
+            nonce,
         };
         debug!(
             target: LOG_TARGET,
@@ -313,7 +917,7 @@ impl DhtActor {
         let broadcast_strategy = BroadcastStrategy::Closest(Box::new(BroadcastClosestRequest {
             n: self.config.num_neighbouring_nodes,
             node_id: network_location_node_id,
-            excluded_peers: Vec::new(),
+            excluded_peers: self.banned_peers(),
         }));
 
         self.outbound_requester
@@ -329,22 +933,35 @@ impl DhtActor {
         Ok(())
     }
 
-    async fn request_stored_messages(&mut self) -> Result<(), DhtOutboundError> {
+    /// Requests stored messages from our closest peers. If `since` is `None`, the persisted high-water mark
+    /// for this node is used, so that only messages received after the last successful request are returned;
+    /// pass `Some(since)` to override this (e.g. to force a full resync with `DateTime::<Utc>::MIN_UTC`).
+    ///
+    /// Note that this only sends the request; the high-water mark itself is only advanced once the
+    /// corresponding response has actually been processed (see [`DhtRequest::SafMessagesProcessed`]), so that a
+    /// response that is lost or never arrives does not cause messages to be skipped on the next sync.
+    async fn request_stored_messages(&mut self, since: Option<DateTime<Utc>>) -> Result<(), DhtOutboundError> {
+        let source = self.node_identity.node_id().clone();
+        let since = since.or_else(|| self.saf_state_store.last_request_time(&source));
+
         let broadcast_strategy = BroadcastStrategy::Closest(Box::new(BroadcastClosestRequest {
             n: self.config.num_neighbouring_nodes,
-            node_id: self.node_identity.node_id().clone(),
-            excluded_peers: Vec::new(),
+            node_id: source.clone(),
+            excluded_peers: self.banned_peers(),
         }));
 
+        let request = match since {
+            Some(since) => StoredMessagesRequest::since(since),
+            None => StoredMessagesRequest::new(),
+        };
+
         self.outbound_requester
             .send_dht_message(
                 broadcast_strategy,
                 NodeDestination::Unknown,
                 OutboundEncryption::EncryptForDestination,
                 DhtMessageType::SafRequestMessages,
-                // TODO: We should track when this node last requested stored messages and ask
-                //       for messages after that date
-                StoredMessagesRequest::new(),
+                request,
             )
             .await?;
 
@@ -356,6 +973,7 @@ impl DhtActor {
 mod test {
     use super::*;
     use crate::test_utils::make_node_identity;
+    use std::time::Duration;
     use tari_shutdown::Shutdown;
     use tari_test_utils::runtime;
 
@@ -440,17 +1058,55 @@ mod test {
 
             rt.spawn(actor.start());
 
+            rt.spawn({
+                let mut requester = requester.clone();
+                async move {
+                    let _ = requester
+                        .send_discover(CommsPublicKey::default(), None, NodeDestination::Unknown)
+                        .await;
+                }
+            });
+
             rt.block_on(async move {
-                requester
-                    .send_discover(CommsPublicKey::default(), None, NodeDestination::Unknown)
-                    .await
-                    .unwrap();
                 let request = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
                 assert_eq!(request.dht_message_type, DhtMessageType::Discover);
             });
         });
     }
 
+    #[test]
+    fn send_discover_request_times_out() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let (out_tx, _out_rx) = mpsc::channel(1);
+            let (actor_tx, actor_rx) = mpsc::channel(1);
+            let mut requester = DhtRequester::new(actor_tx);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    discovery_request_timeout: Duration::from_millis(1),
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.spawn(actor.start());
+
+            rt.block_on(async move {
+                let result = requester
+                    .send_discover(CommsPublicKey::default(), None, NodeDestination::Unknown)
+                    .await;
+                assert!(matches!(result, Err(DhtActorError::DiscoveryTimeout)));
+            });
+        });
+    }
+
     #[test]
     fn insert_message_signature() {
         runtime::test_async(|rt| {
@@ -485,4 +1141,538 @@ mod test {
             });
         });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn penalize_peer() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let peer_node_id = node_identity.node_id().clone();
+            let (out_tx, _) = mpsc::channel(1);
+            let (actor_tx, actor_rx) = mpsc::channel(1);
+            let mut requester = DhtRequester::new(actor_tx);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    ban_threshold: 10,
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.spawn(actor.start());
+
+            rt.block_on(async move {
+                let score = requester.get_peer_score(peer_node_id.clone()).await.unwrap();
+                assert_eq!(score, 0);
+
+                requester.penalize_peer(peer_node_id.clone(), 20).await.unwrap();
+                let score = requester.get_peer_score(peer_node_id).await.unwrap();
+                assert_eq!(score, 20);
+            });
+        });
+    }
+
+    #[test]
+    fn periodic_join_refresh() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let (out_tx, mut out_rx) = mpsc::channel(2);
+            let (_actor_tx, actor_rx) = mpsc::channel(1);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    join_refresh_interval: Duration::from_millis(10),
+                    timer_jitter_factor: 0.0,
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.spawn(actor.start());
+
+            rt.block_on(async move {
+                // The initial auto-join is disabled, so the only Join messages seen come from the refresh timer
+                let request = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
+                assert_eq!(request.dht_message_type, DhtMessageType::Join);
+                let request = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
+                assert_eq!(request.dht_message_type, DhtMessageType::Join);
+            });
+        });
+    }
+
+    #[test]
+    fn request_stored_messages() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let (out_tx, mut out_rx) = mpsc::channel(1);
+            let (actor_tx, actor_rx) = mpsc::channel(1);
+            let mut requester = DhtRequester::new(actor_tx);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.spawn(actor.start());
+
+            rt.block_on(async move {
+                requester.request_stored_messages(None).await.unwrap();
+                let request = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
+                assert_eq!(request.dht_message_type, DhtMessageType::SafRequestMessages);
+            });
+        });
+    }
+
+    #[test]
+    fn saf_high_water_mark_only_advances_once_processed() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let node_id = node_identity.node_id().clone();
+            let (out_tx, mut out_rx) = mpsc::channel(2);
+            let (_actor_tx, actor_rx) = mpsc::channel(1);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let mut actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.block_on(async move {
+                // Sending the request alone must not be enough to advance the high-water mark: only the
+                // (separately reported) processed response should do that.
+                actor
+                    .handle_request(DhtRequest::RequestStoredMessages { since: None })
+                    .await;
+                let _ = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
+                assert!(actor.saf_state_store.last_request_time(&node_id).is_none());
+
+                let processed_at = Utc::now();
+                actor
+                    .handle_request(DhtRequest::SafMessagesProcessed {
+                        last_message_timestamp: processed_at,
+                    })
+                    .await;
+                assert_eq!(actor.saf_state_store.last_request_time(&node_id), Some(processed_at));
+
+                actor
+                    .handle_request(DhtRequest::RequestStoredMessages { since: None })
+                    .await;
+                let request = unwrap_oms_send_msg!(out_rx.next().await.unwrap());
+                assert_eq!(request.dht_message_type, DhtMessageType::SafRequestMessages);
+            });
+        });
+    }
+
+    fn sign_gossip_fixture(
+        node_identity: &NodeIdentity,
+        addresses: &[String],
+        peer_features: u32,
+        created_at: i64,
+        message_kind: GossipMessageKind,
+    ) -> (Vec<u8>, Vec<u8>)
+    {
+        let node_id = node_identity.node_id().to_vec();
+        let buffer = DhtActor::construct_signable_buffer(&node_id, addresses, peer_features, created_at, message_kind);
+        let signature = Signature::sign(node_identity.secret_key().clone(), &buffer)
+            .unwrap()
+            .to_bytes();
+        (node_id, signature)
+    }
+
+    #[test]
+    fn verify_gossip_message_accepts_valid_signature() {
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let (node_id, signature) =
+            sign_gossip_fixture(&node_identity, &addresses, peer_features, created_at, GossipMessageKind::Join);
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_tampered_signature() {
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let (node_id, mut signature) =
+            sign_gossip_fixture(&node_identity, &addresses, peer_features, created_at, GossipMessageKind::Join);
+        signature[0] ^= 0xff;
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::InvalidMessageSignature)));
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_mismatched_node_id() {
+        let node_identity = make_node_identity();
+        let impersonated_node_id = make_node_identity().node_id().to_vec();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        // Sign over the impersonated node id (as a forger would), not our own, so only the node id check is
+        // exercised.
+        let buffer = DhtActor::construct_signable_buffer(
+            &impersonated_node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            GossipMessageKind::Join,
+        );
+        let signature = Signature::sign(node_identity.secret_key().clone(), &buffer)
+            .unwrap()
+            .to_bytes();
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &impersonated_node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::InvalidNodeIdMapping)));
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_stale_timestamp() {
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = (Utc::now() - chrono::Duration::seconds(120)).timestamp();
+        let (node_id, signature) =
+            sign_gossip_fixture(&node_identity, &addresses, peer_features, created_at, GossipMessageKind::Join);
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::MessageTimestampOutOfRange)));
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_created_at_that_would_overflow_skew_check() {
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        // A self-consistent but adversarial `created_at`: `now - created_at` overflows `i64`, which must be
+        // rejected rather than panicking or wrapping around into an apparently-valid skew.
+        let created_at = i64::MIN;
+        let (node_id, signature) =
+            sign_gossip_fixture(&node_identity, &addresses, peer_features, created_at, GossipMessageKind::Join);
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::MessageTimestampOutOfRange)));
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_shifted_address_boundary() {
+        // Two distinct address lists that concatenate to the same bytes with no framing; length-prefixing each
+        // address must make these produce different signable buffers (and thus the signature for one must not
+        // verify against the other).
+        let node_identity = make_node_identity();
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let original = vec!["/ip4/1.2.3.4/tcp/9000".to_string(), "a".to_string()];
+        let shifted = vec!["/ip4/1.2.3.4/tcp/9000a".to_string()];
+        assert_ne!(original.concat(), "");
+        assert_eq!(original.concat(), shifted.concat());
+
+        let (node_id, signature) =
+            sign_gossip_fixture(&node_identity, &original, peer_features, created_at, GossipMessageKind::Join);
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &shifted,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::Join,
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::InvalidMessageSignature)));
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_message_kind_replayed_as_another_kind() {
+        // A signature produced for a Join must not verify when replayed as a Discover or DiscoveryResponse: the
+        // domain tag folded into the signed buffer must tie the signature to one message kind.
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let (node_id, signature) =
+            sign_gossip_fixture(&node_identity, &addresses, peer_features, created_at, GossipMessageKind::Join);
+
+        for replayed_as in &[
+            GossipMessageKind::Discover { nonce: 0 },
+            GossipMessageKind::DiscoveryResponse { nonce: 0 },
+        ] {
+            let result = DhtActor::verify_gossip_message(
+                node_identity.public_key(),
+                &node_id,
+                &addresses,
+                peer_features,
+                created_at,
+                &signature,
+                *replayed_as,
+                chrono::Duration::seconds(60),
+            );
+
+            assert!(matches!(result, Err(DhtActorError::InvalidMessageSignature)));
+        }
+    }
+
+    #[test]
+    fn verify_gossip_message_rejects_discovery_response_replayed_with_different_nonce() {
+        // A signature produced for a DiscoveryResponse carrying one nonce must not verify against a different
+        // nonce: otherwise an attacker who observed a signed DiscoveryResponse for nonce X could repackage it
+        // with an arbitrary nonce Y to complete someone else's unrelated pending SendDiscover.
+        let node_identity = make_node_identity();
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let (node_id, signature) = sign_gossip_fixture(
+            &node_identity,
+            &addresses,
+            peer_features,
+            created_at,
+            GossipMessageKind::DiscoveryResponse { nonce: 1 },
+        );
+
+        let result = DhtActor::verify_gossip_message(
+            node_identity.public_key(),
+            &node_id,
+            &addresses,
+            peer_features,
+            created_at,
+            &signature,
+            GossipMessageKind::DiscoveryResponse { nonce: 2 },
+            chrono::Duration::seconds(60),
+        );
+
+        assert!(matches!(result, Err(DhtActorError::InvalidMessageSignature)));
+    }
+
+    #[test]
+    fn join_received_with_forged_signature_penalizes_sender() {
+        runtime::test_async(|rt| {
+            let node_identity = make_node_identity();
+            let impersonator = make_node_identity();
+            let impersonator_public_key = impersonator.public_key().clone();
+            let impersonator_node_id = impersonator.node_id().clone();
+            let (out_tx, _out_rx) = mpsc::channel(1);
+            let (actor_tx, actor_rx) = mpsc::channel(1);
+            let mut raw_sender = actor_tx.clone();
+            let mut requester = DhtRequester::new(actor_tx);
+            let outbound_requester = OutboundMessageRequester::new(out_tx);
+            let shutdown = Shutdown::new();
+            let actor = DhtActor::new(
+                DhtConfig {
+                    enable_auto_join: false,
+                    enable_auto_stored_message_request: false,
+                    ..Default::default()
+                },
+                node_identity,
+                outbound_requester,
+                actor_rx,
+                shutdown.to_signal(),
+            );
+
+            rt.spawn(actor.start());
+
+            rt.block_on(async move {
+                let message = JoinMessage {
+                    node_id: impersonator_node_id.to_vec(),
+                    addresses: vec!["/ip4/127.0.0.1/tcp/9000".to_string()],
+                    peer_features: 0,
+                    created_at: Utc::now().timestamp(),
+                    signature: vec![0u8; 64],
+                };
+
+                raw_sender
+                    .send(DhtRequest::JoinReceived {
+                        origin_public_key: impersonator_public_key,
+                        message: Box::new(message),
+                    })
+                    .await
+                    .unwrap();
+
+                let score = requester.get_peer_score(impersonator_node_id).await.unwrap();
+                assert_eq!(score, INVALID_GOSSIP_SIGNATURE_PENALTY);
+            });
+        });
+    }
+
+    #[test]
+    fn discovery_response_with_forged_signature_is_rejected_and_penalizes_sender() {
+        let node_identity = make_node_identity();
+        let impersonator = make_node_identity();
+        let impersonator_public_key = impersonator.public_key().clone();
+        let impersonator_node_id = impersonator.node_id().clone();
+        let (out_tx, _out_rx) = mpsc::channel(1);
+        let (_actor_tx, actor_rx) = mpsc::channel(1);
+        let outbound_requester = OutboundMessageRequester::new(out_tx);
+        let shutdown = Shutdown::new();
+        let mut actor = DhtActor::new(
+            DhtConfig {
+                enable_auto_join: false,
+                enable_auto_stored_message_request: false,
+                ..Default::default()
+            },
+            node_identity,
+            outbound_requester,
+            actor_rx,
+            shutdown.to_signal(),
+        );
+
+        let nonce = 42u64;
+        let (reply_tx, mut reply_rx) = oneshot::channel();
+        actor.pending_discoveries.insert(nonce, (reply_tx, Instant::now()));
+
+        // A forged response: `signature` doesn't actually cover this payload, so it must not resolve the
+        // pending discovery, and the sender must be penalized exactly like a forged Join/Discover.
+        let message = DiscoveryResponseMessage {
+            node_id: impersonator_node_id.to_vec(),
+            addresses: vec!["/ip4/127.0.0.1/tcp/9000".to_string()],
+            peer_features: 0,
+            created_at: Utc::now().timestamp(),
+            signature: vec![0u8; 64],
+            nonce,
+        };
+
+        actor.handle_discovery_response(impersonator_public_key, message);
+
+        assert!(reply_rx.try_recv().unwrap().is_none());
+        assert_eq!(actor.peer_score(&impersonator_node_id), INVALID_GOSSIP_SIGNATURE_PENALTY);
+    }
+
+    #[test]
+    fn join_received_with_replayed_signature_is_rejected_and_penalizes_sender() {
+        // A validly-signed Join is accepted the first time, but replaying the exact same message again must be
+        // rejected (and penalized) even though the signature still verifies cryptographically: the signature
+        // cache is the stop-gap against exact replay ahead of richer anti-replay binding (e.g. a nonce).
+        let node_identity = make_node_identity();
+        let sender = make_node_identity();
+        let sender_public_key = sender.public_key().clone();
+        let sender_node_id = sender.node_id().clone();
+        let (out_tx, _out_rx) = mpsc::channel(1);
+        let (_actor_tx, actor_rx) = mpsc::channel(1);
+        let outbound_requester = OutboundMessageRequester::new(out_tx);
+        let shutdown = Shutdown::new();
+        let mut actor = DhtActor::new(
+            DhtConfig {
+                enable_auto_join: false,
+                enable_auto_stored_message_request: false,
+                ..Default::default()
+            },
+            node_identity,
+            outbound_requester,
+            actor_rx,
+            shutdown.to_signal(),
+        );
+
+        let addresses = vec!["/ip4/127.0.0.1/tcp/9000".to_string()];
+        let peer_features = 0u32;
+        let created_at = Utc::now().timestamp();
+        let (node_id, signature) = sign_gossip_fixture(
+            &sender,
+            &addresses,
+            peer_features,
+            created_at,
+            GossipMessageKind::Join,
+        );
+
+        let first = JoinMessage {
+            node_id: node_id.clone(),
+            addresses: addresses.clone(),
+            peer_features,
+            created_at,
+            signature: signature.clone(),
+        };
+        actor.handle_join_received(sender_public_key.clone(), first);
+        assert_eq!(actor.peer_score(&sender_node_id), 0);
+
+        let replayed = JoinMessage {
+            node_id,
+            addresses,
+            peer_features,
+            created_at,
+            signature,
+        };
+        actor.handle_join_received(sender_public_key, replayed);
+        assert_eq!(actor.peer_score(&sender_node_id), INVALID_GOSSIP_SIGNATURE_PENALTY);
+    }
+}